@@ -0,0 +1,174 @@
+//! Node/broker telemetry exported as OpenTelemetry metrics and scraped via Prometheus
+//!
+//! The values collected in [`crate::node::NodeInfo`]/[`crate::node::BrokerInfo`] (connections,
+//! load averages, memory/disk usage, cpuload, uptime, node status) are mirrored here as
+//! OpenTelemetry gauges so they can be scraped in Prometheus text format without
+//! going through the gRPC status API and reshaping JSON by hand.
+//!
+//! The HTTP handler never touches `systemstat` directly: samples are produced on the same
+//! cadence as [`crate::node::Node::update_cpuload`] and cached, so a scrape is always a cheap
+//! read of the last snapshot rather than a blocking syscall on the request path.
+
+use std::sync::RwLock;
+
+use opentelemetry::metrics::{Gauge, Meter};
+use opentelemetry::KeyValue;
+use opentelemetry_prometheus::PrometheusExporter;
+use opentelemetry_sdk::metrics::SdkMeterProvider;
+use prometheus::{Encoder, TextEncoder};
+
+use crate::node::NodeInfo;
+use crate::types::NodeId;
+
+/// Builds a `Meter` backed by a fresh `SdkMeterProvider` with a Prometheus exporter
+/// installed as its reader, and returns the exporter alongside it.
+///
+/// The exporter passed to [`NodeMetrics::new`] MUST be this same instance: an OTel
+/// Prometheus exporter only receives data if it was registered via
+/// `MeterProviderBuilder::with_reader` on the provider that produced the meter, so
+/// building a second, disconnected exporter later would leave `render()` reading an
+/// always-empty registry.
+pub fn build_meter(scope: &'static str) -> crate::Result<(Meter, PrometheusExporter)> {
+    let exporter = opentelemetry_prometheus::exporter().build()?;
+    let provider = SdkMeterProvider::builder().with_reader(exporter.clone()).build();
+    let meter = provider.meter(scope);
+    Ok((meter, exporter))
+}
+
+/// Labeled OpenTelemetry instruments for a single node's telemetry.
+///
+/// All instruments are re-recorded on every sample rather than accumulated, since the
+/// underlying values (load averages, memory, disk) are themselves point-in-time gauges.
+pub struct NodeMetrics {
+    node_id: NodeId,
+    node_name: String,
+    exporter: PrometheusExporter,
+    connections: Gauge<u64>,
+    load1: Gauge<f64>,
+    load5: Gauge<f64>,
+    load15: Gauge<f64>,
+    memory_total: Gauge<u64>,
+    memory_used: Gauge<u64>,
+    memory_free: Gauge<u64>,
+    disk_total: Gauge<u64>,
+    disk_free: Gauge<u64>,
+    cpuload: Gauge<f64>,
+    uptime_secs: Gauge<u64>,
+    /// Point-in-time `Running(count)`, re-recorded on every sample rather than
+    /// accumulated — a `Counter` here would make this grow every cadence tick instead of
+    /// reflecting the current status.
+    running: Gauge<u64>,
+    last_sample: RwLock<Option<NodeInfo>>,
+}
+
+impl NodeMetrics {
+    /// Builds the instrument set on `meter`, rendering through `exporter`.
+    ///
+    /// `meter` and `exporter` must come from the same call to [`build_meter`] — the
+    /// exporter has to be the reader registered on the provider that produced `meter`, or
+    /// `render()` will always see an empty registry.
+    ///
+    /// `node_id`/`node_name` are attached as labels on every gauge so a multi-node scrape
+    /// target (or a federated Prometheus) can tell members apart.
+    pub fn new(meter: &Meter, exporter: PrometheusExporter, node_id: NodeId, node_name: String) -> crate::Result<Self> {
+        Ok(Self {
+            node_id,
+            node_name,
+            exporter,
+            connections: meter.u64_gauge("rmqtt_node_connections").with_description("Current connection count").build(),
+            load1: meter.f64_gauge("rmqtt_node_load1").with_description("1-minute load average").build(),
+            load5: meter.f64_gauge("rmqtt_node_load5").with_description("5-minute load average").build(),
+            load15: meter.f64_gauge("rmqtt_node_load15").with_description("15-minute load average").build(),
+            memory_total: meter.u64_gauge("rmqtt_node_memory_total_bytes").build(),
+            memory_used: meter.u64_gauge("rmqtt_node_memory_used_bytes").build(),
+            memory_free: meter.u64_gauge("rmqtt_node_memory_free_bytes").build(),
+            disk_total: meter.u64_gauge("rmqtt_node_disk_total_bytes").build(),
+            disk_free: meter.u64_gauge("rmqtt_node_disk_free_bytes").build(),
+            cpuload: meter.f64_gauge("rmqtt_node_cpuload_percent").build(),
+            uptime_secs: meter.u64_gauge("rmqtt_node_uptime_seconds").build(),
+            running: meter
+                .u64_gauge("rmqtt_node_status_running")
+                .with_description("Per-status Running(count) observed on the last sample")
+                .build(),
+            last_sample: RwLock::new(None),
+        })
+    }
+
+    fn labels(&self) -> [KeyValue; 2] {
+        [KeyValue::new("node_id", self.node_id.to_string()), KeyValue::new("node_name", self.node_name.clone())]
+    }
+
+    /// Records a fresh sample into the OpenTelemetry instruments and caches it for scrapes.
+    ///
+    /// Called from the same `busy_update_interval` cadence as `update_cpuload`, never from
+    /// the HTTP handler, so a scrape never triggers a blocking `systemstat` call.
+    pub fn record(&self, info: &NodeInfo, cpuload: f32) {
+        let labels = self.labels();
+        self.connections.record(info.connections.max(0) as u64, &labels);
+        self.load1.record(info.load1 as f64, &labels);
+        self.load5.record(info.load5 as f64, &labels);
+        self.load15.record(info.load15 as f64, &labels);
+        self.memory_total.record(info.memory_total, &labels);
+        self.memory_used.record(info.memory_used, &labels);
+        self.memory_free.record(info.memory_free, &labels);
+        self.disk_total.record(info.disk_total, &labels);
+        self.disk_free.record(info.disk_free, &labels);
+        self.cpuload.record(cpuload as f64, &labels);
+        self.uptime_secs.record(info.uptime_secs().max(0) as u64, &labels);
+        self.running.record(info.node_status.running() as u64, &labels);
+
+        *self.last_sample.write().unwrap() = Some(info.clone());
+    }
+
+    /// Renders the current registry in Prometheus text exposition format.
+    ///
+    /// This is the HTTP handler entry point: it never re-samples the system, it just
+    /// encodes whatever OpenTelemetry has collected up to the last `record` call.
+    pub fn render(&self) -> crate::Result<String> {
+        let metric_families = self.exporter.registry().gather();
+        let encoder = TextEncoder::new();
+        let mut buf = Vec::new();
+        encoder.encode(&metric_families, &mut buf)?;
+        Ok(String::from_utf8(buf)?)
+    }
+
+    /// Returns the last cached [`NodeInfo`] sample, if any has been recorded yet.
+    pub fn last_sample(&self) -> Option<NodeInfo> {
+        self.last_sample.read().unwrap().clone()
+    }
+}
+
+/// Serves the Prometheus text-format scrape endpoint at `GET /metrics` on `addr`.
+///
+/// This only ever reads the cached sample via [`NodeMetrics::render`]; it does not spawn
+/// any polling of its own. Binding is fallible (e.g. the port is already in use) and
+/// returned as a `Result` rather than panicking, so a bad metrics config can't take the
+/// rest of the broker down with it.
+pub async fn serve(metrics: std::sync::Arc<NodeMetrics>, addr: std::net::SocketAddr) -> crate::Result<()> {
+    use hyper::service::{make_service_fn, service_fn};
+    use hyper::{Body, Response, Server};
+
+    let make_svc = make_service_fn(move |_conn| {
+        let metrics = metrics.clone();
+        async move {
+            Ok::<_, std::convert::Infallible>(service_fn(move |_req| {
+                let metrics = metrics.clone();
+                async move {
+                    let body = metrics.render().unwrap_or_default();
+                    Ok::<_, std::convert::Infallible>(
+                        Response::builder()
+                            .header("Content-Type", "text/plain; version=0.0.4")
+                            .body(Body::from(body))
+                            .unwrap(),
+                    )
+                }
+            }))
+        }
+    });
+
+    let server = Server::try_bind(&addr)
+        .map_err(|e| anyhow::anyhow!("failed to bind metrics endpoint on {addr}: {e:?}"))?
+        .serve(make_svc);
+    server.await.map_err(|e| anyhow::anyhow!(e))?;
+    Ok(())
+}
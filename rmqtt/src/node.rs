@@ -20,7 +20,7 @@
 //! - Distributed architecture support via gRPC
 //!
 
-use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicUsize, Ordering};
 use std::time::Duration;
 
 use serde::{Deserialize, Serialize};
@@ -30,8 +30,11 @@ use systemstat::Platform;
 use crate::context::ServerContext;
 #[cfg(feature = "grpc")]
 use crate::grpc::{GrpcClient, GrpcServer};
+#[cfg(feature = "metrics")]
+use crate::metrics::NodeMetrics;
 use crate::types::{NodeId, TimestampMillis};
 use crate::utils::timestamp_millis;
+use crate::workers::Worker;
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
@@ -49,6 +52,91 @@ pub struct Node {
     cpuload: AtomicI64,
     cached_busy: AtomicBool,
     cached_time: AtomicI64,
+    /// Total process count, resampled on the `busy_update_interval` cadence alongside
+    /// cpuload rather than on every `node_info` call — a full process-table walk is a
+    /// blocking, syscall-heavy scan we don't want on the query path.
+    cached_proc_total: AtomicUsize,
+    static_sys_info: StaticSysInfo,
+    /// Stable identifier for the physical/virtual host, read once at startup.
+    machine_id: String,
+    /// Random identifier generated fresh on every process launch.
+    instance_id: String,
+    /// Directory backing the broker's persistent data, used to pick which mount's disk
+    /// stats are reported instead of summing every filesystem.
+    data_dir: std::sync::RwLock<std::path::PathBuf>,
+    /// Liveness of known cluster members, refreshed through [`Node::record_heartbeat`].
+    /// Only this node's own entry is refreshed (by its `update_cpuload` cadence); no
+    /// inbound gRPC heartbeat feeds peer entries.
+    cluster_members: tokio::sync::RwLock<std::collections::HashMap<NodeId, ClusterMember>>,
+    /// This node's own gRPC listen address, recorded once [`Node::start_grpc_server`] binds.
+    self_grpc_addr: std::sync::OnceLock<String>,
+    #[cfg(feature = "metrics")]
+    metrics: std::sync::OnceLock<std::sync::Arc<NodeMetrics>>,
+}
+
+/// System facts that rarely change over the lifetime of a process, gathered once at
+/// startup instead of on every `node_info` call.
+#[derive(Debug, Clone, Default)]
+struct StaticSysInfo {
+    os_type: String,
+    os_release: String,
+    cpu_num: usize,
+    cpu_speed: u64,
+    max_fds: u64,
+}
+
+impl StaticSysInfo {
+    fn collect() -> Self {
+        let mut sys = sysinfo::System::new();
+        sys.refresh_cpu_all();
+        let cpu_num = sys.cpus().len();
+        let cpu_speed = sys.cpus().first().map(|c| c.frequency()).unwrap_or_default();
+        Self {
+            os_type: sysinfo::System::name().unwrap_or_default(),
+            os_release: sysinfo::System::os_version().unwrap_or_default(),
+            cpu_num,
+            cpu_speed,
+            max_fds: Self::max_fds(),
+        }
+    }
+
+    #[cfg(unix)]
+    fn max_fds() -> u64 {
+        let mut limit = libc::rlimit { rlim_cur: 0, rlim_max: 0 };
+        if unsafe { libc::getrlimit(libc::RLIMIT_NOFILE, &mut limit) } == 0 {
+            limit.rlim_cur as u64
+        } else {
+            0
+        }
+    }
+
+    #[cfg(not(unix))]
+    fn max_fds() -> u64 {
+        0
+    }
+}
+
+/// Reads a stable, host-scoped identifier so a controller can correlate a broker to the
+/// same physical hardware across restarts.
+///
+/// On Linux this is `/etc/machine-id` (the same id systemd exposes over D-Bus); on other
+/// platforms we fall back to a hash of the hostname, since there's no portable equivalent.
+fn read_machine_id() -> String {
+    #[cfg(target_os = "linux")]
+    {
+        if let Ok(id) = std::fs::read_to_string("/etc/machine-id") {
+            let id = id.trim();
+            if !id.is_empty() {
+                return id.to_string();
+            }
+        }
+    }
+
+    use std::hash::{Hash, Hasher};
+    let hostname = sysinfo::System::host_name().unwrap_or_default();
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    hostname.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
 }
 
 impl Default for Node {
@@ -82,14 +170,62 @@ impl Node {
             cpuload: AtomicI64::new(0),
             cached_busy: AtomicBool::new(false),
             cached_time: AtomicI64::new(0),
+            cached_proc_total: AtomicUsize::new(0),
+            static_sys_info: StaticSysInfo::collect(),
+            machine_id: read_machine_id(),
+            instance_id: uuid::Uuid::new_v4().to_string(),
+            data_dir: std::sync::RwLock::new(std::env::current_dir().unwrap_or_default()),
+            cluster_members: tokio::sync::RwLock::new(std::collections::HashMap::new()),
+            self_grpc_addr: std::sync::OnceLock::new(),
+            #[cfg(feature = "metrics")]
+            metrics: std::sync::OnceLock::new(),
         }
     }
 
+    /// Installs the OpenTelemetry/Prometheus metrics registry for this node.
+    ///
+    /// Must be called once at startup, before the `busy_update_interval` loop begins
+    /// calling [`Node::update_cpuload`], or samples are simply dropped instead of recorded.
+    ///
+    /// Builds the `Meter`/Prometheus exporter pair itself via
+    /// [`crate::metrics::build_meter`] rather than taking an already-built `Meter`, since
+    /// the exporter must be the same instance registered as the reader on the provider
+    /// that produced the meter — otherwise it never receives any data. Returns the
+    /// registry so the caller can wire it into [`crate::metrics::serve`].
+    #[cfg(feature = "metrics")]
+    pub async fn init_metrics(&self, scx: &ServerContext) -> crate::Result<std::sync::Arc<NodeMetrics>> {
+        let node_id = self.id;
+        let node_name = self.name(scx, node_id).await;
+        let (meter, exporter) = crate::metrics::build_meter("rmqtt")?;
+        let metrics = std::sync::Arc::new(NodeMetrics::new(&meter, exporter, node_id, node_name)?);
+        let _ = self.metrics.set(metrics.clone());
+        Ok(metrics)
+    }
+
     #[inline]
     pub fn id(&self) -> NodeId {
         self.id
     }
 
+    /// Stable id for the physical/virtual host this process is running on.
+    #[inline]
+    pub fn machine_id(&self) -> &str {
+        &self.machine_id
+    }
+
+    /// Random id generated fresh on this process launch; changes across restarts.
+    #[inline]
+    pub fn instance_id(&self) -> &str {
+        &self.instance_id
+    }
+
+    /// Sets the directory backing the broker's persistent data, so `node_info` reports
+    /// disk stats for the mount that actually holds broker data.
+    #[inline]
+    pub fn set_data_dir(&self, data_dir: impl Into<std::path::PathBuf>) {
+        *self.data_dir.write().unwrap() = data_dir.into();
+    }
+
     #[inline]
     pub async fn name(&self, scx: &ServerContext, id: NodeId) -> String {
         scx.extends.shared().await.node_name(id)
@@ -108,18 +244,40 @@ impl Node {
     }
 
     #[cfg(feature = "grpc")]
-    pub fn start_grpc_server(
+    pub async fn start_grpc_server(
         &self,
         scx: ServerContext,
         server_addr: std::net::SocketAddr,
         reuseaddr: bool,
         reuseport: bool,
     ) {
-        tokio::spawn(async move {
-            if let Err(e) = GrpcServer::new(scx).listen_and_serve(server_addr, reuseaddr, reuseport).await {
-                log::error!("listen and serve failure, {e:?}, laddr: {server_addr:?}");
-            }
-        });
+        let _ = self.self_grpc_addr.set(server_addr.to_string());
+        let registry = scx.workers.clone();
+        registry
+            .spawn_long_running("grpc-server", async move {
+                GrpcServer::new(scx)
+                    .listen_and_serve(server_addr, reuseaddr, reuseport)
+                    .await
+                    .map_err(|e| anyhow::anyhow!("listen and serve failure, {e:?}, laddr: {server_addr:?}"))
+            })
+            .await;
+    }
+
+    /// Starts every long-running background task this node owns — the cpuload sampler
+    /// and, when configured, the gRPC server — registering both on `scx.workers` instead
+    /// of leaving them as untracked detached `tokio::spawn`s. Call once at broker startup.
+    pub async fn start_background_workers(
+        self: std::sync::Arc<Self>,
+        scx: ServerContext,
+        #[cfg(feature = "grpc")] grpc_server: Option<(std::net::SocketAddr, bool, bool)>,
+    ) {
+        let registry = scx.workers.clone();
+        self.clone().spawn_cpuload_worker(scx.clone(), &registry).await;
+
+        #[cfg(feature = "grpc")]
+        if let Some((server_addr, reuseaddr, reuseport)) = grpc_server {
+            self.start_grpc_server(scx, server_addr, reuseaddr, reuseport).await;
+        }
     }
 
     #[inline]
@@ -142,8 +300,9 @@ impl Node {
     }
 
     #[inline]
-    pub async fn broker_info(&self, scx: &ServerContext) -> BrokerInfo {
+    pub async fn broker_info(&self, scx: &ServerContext, format: TimeFormat) -> BrokerInfo {
         let node_id = self.id;
+        let now = chrono::Local::now();
         BrokerInfo {
             version: format!("rmqtt/{VERSION}-{RUSTC_BUILD_TIME}"),
             rustc_version: RUSTC_VERSION.to_string(),
@@ -152,33 +311,69 @@ impl Node {
             node_status: self.status(scx).await,
             node_id,
             node_name: self.name(scx, node_id).await, //Runtime::instance().extends.shared().await.node_name(node_id),
-            datetime: chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            datetime: now.format("%Y-%m-%d %H:%M:%S").to_string(),
+            datetime_display: format.render(now.timestamp_millis(), now.format("%Y-%m-%d %H:%M:%S").to_string()),
+            machine_id: self.machine_id.clone(),
+            instance_id: self.instance_id.clone(),
         }
     }
 
     #[inline]
-    pub async fn node_info(&self, scx: &ServerContext) -> NodeInfo {
+    pub async fn node_info(&self, scx: &ServerContext, format: TimeFormat) -> NodeInfo {
         let node_id = self.id;
 
         let sys = systemstat::System::new();
         let boottime = sys.boot_time().map(|t| t.to_string()).unwrap_or_default();
+        let boottime_display = sys
+            .boot_time()
+            .map(|t| format.render(t.timestamp_millis(), t.to_string()))
+            .unwrap_or_default();
         let loadavg = sys.load_average();
         let mem_info = sys.memory();
 
-        let (disk_total, disk_free) = if let Ok(mounts) = sys.mounts() {
-            let total = mounts.iter().map(|m| m.total.as_u64()).sum();
-            let free = mounts.iter().map(|m| m.free.as_u64()).sum();
-            (total, free)
-        } else {
-            (0, 0)
-        };
+        let disks: Vec<DiskInfo> = sys
+            .mounts()
+            .map(|mounts| {
+                mounts
+                    .iter()
+                    .filter(|m| !is_pseudo_fs(&m.fs_type))
+                    .map(|m| DiskInfo {
+                        mount_point: m.fs_mounted_on.clone(),
+                        fs_type: m.fs_type.clone(),
+                        total: m.total.as_u64(),
+                        free: m.free.as_u64(),
+                        used: systemstat::saturating_sub_bytes(m.total, m.free).as_u64(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        // The summed figures are kept for backward compatibility, but now reflect the
+        // filtered (pseudo-fs excluded) total rather than every entry from `sys.mounts()`.
+        let (disk_total, disk_free) = (disks.iter().map(|d| d.total).sum(), disks.iter().map(|d| d.free).sum());
+
+        let data_dir = self.data_dir.read().unwrap().clone();
+        let data_disk = disks
+            .iter()
+            .filter(|d| data_dir.starts_with(&d.mount_point))
+            .max_by_key(|d| d.mount_point.len())
+            .cloned();
+
+        let proc_total = self.cached_proc_total.load(Ordering::Relaxed);
 
         NodeInfo {
             connections: scx.connections.count(),
             boottime,
+            boottime_display,
             load1: loadavg.as_ref().map(|l| l.one).unwrap_or_default(),
             load5: loadavg.as_ref().map(|l| l.five).unwrap_or_default(),
             load15: loadavg.as_ref().map(|l| l.fifteen).unwrap_or_default(),
+            max_fds: self.static_sys_info.max_fds,
+            cpu_num: self.static_sys_info.cpu_num,
+            cpu_speed: self.static_sys_info.cpu_speed,
+            os_type: self.static_sys_info.os_type.clone(),
+            os_release: self.static_sys_info.os_release.clone(),
+            proc_total,
             memory_total: mem_info.as_ref().map(|m| m.total.as_u64()).unwrap_or_default(),
             memory_free: mem_info.as_ref().map(|m| m.free.as_u64()).unwrap_or_default(),
             memory_used: mem_info
@@ -187,10 +382,13 @@ impl Node {
                 .unwrap_or_default(),
             disk_total,
             disk_free,
+            data_disk,
+            disks,
             node_status: self.status(scx).await,
             node_id,
             node_name: self.name(scx, node_id).await, //Runtime::instance().extends.shared().await.node_name(node_id),
             uptime: self.uptime(),
+            uptime_secs: (chrono::Local::now() - self.start_time).num_seconds(),
             version: format!("rmqtt/{VERSION}-{RUSTC_BUILD_TIME}"),
             rustc_version: RUSTC_VERSION.to_string(),
         }
@@ -241,7 +439,7 @@ impl Node {
         self.cpuload.load(Ordering::SeqCst) as f32 / 100.0
     }
 
-    pub async fn update_cpuload(&self) {
+    pub async fn update_cpuload(&self, scx: &ServerContext) {
         let sys = systemstat::System::new();
         let cpuload_aggr = sys.cpu_load_aggregate().ok();
         tokio::time::sleep(Duration::from_secs(2)).await;
@@ -261,6 +459,113 @@ impl Node {
             .unwrap_or_default();
 
         self.cpuload.store(cpuload as i64, Ordering::SeqCst);
+
+        let mut procs = sysinfo::System::new();
+        procs.refresh_processes(sysinfo::ProcessesToUpdate::All, true);
+        self.cached_proc_total.store(procs.processes().len(), Ordering::Relaxed);
+
+        #[cfg(feature = "metrics")]
+        if let Some(metrics) = self.metrics.get() {
+            let info = self.node_info(scx, TimeFormat::Absolute).await;
+            metrics.record(&info, self.cpuload());
+        }
+
+        let node_id = self.id;
+        let node_name = self.name(scx, node_id).await;
+        let hostname = sysinfo::System::host_name().unwrap_or_default();
+        let grpc_addr = self.self_grpc_addr.get().cloned().unwrap_or_default();
+        self.record_heartbeat(node_id, node_name, hostname, grpc_addr).await;
+    }
+
+    /// Records a heartbeat for `node_id`, refreshing its last-seen timestamp so
+    /// [`Node::handle_get_cluster_status`] can report liveness.
+    ///
+    /// [`Node::update_cpuload`] calls this for the node's own self-heartbeat on every
+    /// cadence tick, which is the only caller in this tree today — `cluster_members` can
+    /// therefore only ever contain this node's own entry. A real multi-node cluster needs
+    /// an inbound gRPC heartbeat RPC (peers call it, the handler calls this method with
+    /// the sender's id) plus an outbound client loop that pushes this node's heartbeat to
+    /// its peers; both live in `crate::grpc`, which is not part of this source tree, so
+    /// they aren't implemented here. This method is the ready-made integration point for
+    /// that handler once it exists.
+    pub async fn record_heartbeat(&self, node_id: NodeId, node_name: String, hostname: String, grpc_addr: String) {
+        self.cluster_members
+            .write()
+            .await
+            .insert(node_id, ClusterMember { node_id, node_name, hostname, grpc_addr, last_seen: timestamp_millis() });
+    }
+
+    /// Returns both the per-node membership/liveness detail and the existing aggregated
+    /// [`NodeInfo`] rollup in one call.
+    ///
+    /// `members` is only ever populated from [`Node::record_heartbeat`]; until a real
+    /// inbound gRPC heartbeat RPC calls it for peers (see the caveat on
+    /// [`Node::record_heartbeat`]), this reports a cluster of exactly one node.
+    pub async fn handle_get_cluster_status(&self, scx: &ServerContext, format: TimeFormat) -> ClusterStatus {
+        let now = timestamp_millis();
+        let members = self
+            .cluster_members
+            .read()
+            .await
+            .values()
+            .map(|m| ClusterMemberStatus {
+                node_id: m.node_id,
+                node_name: m.node_name.clone(),
+                hostname: m.hostname.clone(),
+                grpc_addr: m.grpc_addr.clone(),
+                is_up: now - m.last_seen < self.busy_update_interval.as_millis() as TimestampMillis * 3,
+                last_seen_secs_ago: (now - m.last_seen) / 1000,
+                last_seen_display: format.render(
+                    m.last_seen,
+                    chrono::DateTime::<chrono::Local>::from(
+                        std::time::UNIX_EPOCH + std::time::Duration::from_millis(m.last_seen.max(0) as u64),
+                    )
+                    .format("%Y-%m-%d %H:%M:%S")
+                    .to_string(),
+                ),
+            })
+            .collect();
+
+        ClusterStatus { members, aggregated: self.node_info(scx, format).await }
+    }
+
+    /// Lists every background task registered on `scx.workers` (cpuload sampler, gRPC
+    /// server, future scrub/resync jobs), with its Active/Idle/Dead state, completed
+    /// iterations and last error.
+    ///
+    /// This is the Node-side implementation only; there is no gRPC method exposing it
+    /// yet, since the service definition and handler dispatch live in `crate::grpc`,
+    /// which is not part of this source tree. Wiring a gRPC method is a matter of
+    /// forwarding its request to this method once that layer exists.
+    pub async fn handle_list_workers(&self, scx: &ServerContext) -> Vec<crate::workers::WorkerStatus> {
+        scx.workers.list().await
+    }
+
+    /// Registers the cpuload sampler on `registry` instead of leaving it as a bare
+    /// detached `tokio::spawn`, so its liveness (Active/Idle/Dead) and iteration count
+    /// become observable through [`crate::workers::WorkerRegistry::list`].
+    pub async fn spawn_cpuload_worker(self: std::sync::Arc<Self>, scx: ServerContext, registry: &crate::workers::WorkerRegistry) {
+        let interval = self.busy_update_interval;
+        registry.spawn(CpuloadWorker { node: self, scx }, interval).await;
+    }
+}
+
+/// Wraps [`Node::update_cpuload`] as a [`Worker`] so it's tracked by the registry instead
+/// of being a bare detached `tokio::spawn`.
+struct CpuloadWorker {
+    node: std::sync::Arc<Node>,
+    scx: ServerContext,
+}
+
+#[async_trait::async_trait]
+impl Worker for CpuloadWorker {
+    fn name(&self) -> &str {
+        "cpuload-sampler"
+    }
+
+    async fn run(&self) -> crate::Result<()> {
+        self.node.update_cpuload(&self.scx).await;
+        Ok(())
     }
 }
 
@@ -274,6 +579,19 @@ pub struct BrokerInfo {
     pub node_id: NodeId,
     pub node_name: String,
     pub datetime: String,
+    /// `datetime` rendered per the requested [`TimeFormat`] (absolute or relative).
+    pub datetime_display: String,
+    /// Stable id for the physical/virtual host, read once at startup.
+    ///
+    /// Reaches `to_json()` here; it is not yet threaded into a gRPC status reply, since
+    /// the gRPC message definitions live in `crate::grpc`, which is not part of this
+    /// source tree. Adding the field to that reply type once it exists is the rest of
+    /// this work.
+    pub machine_id: String,
+    /// Random id generated fresh on this process launch; changes across restarts.
+    ///
+    /// Same gRPC-threading caveat as [`BrokerInfo::machine_id`] applies.
+    pub instance_id: String,
 }
 
 impl BrokerInfo {
@@ -286,7 +604,10 @@ impl BrokerInfo {
             "running": self.node_status.is_running(),
             "node_id": self.node_id,
             "node_name": self.node_name,
-            "datetime": self.datetime
+            "datetime": self.datetime,
+            "datetime_display": self.datetime_display,
+            "machine_id": self.machine_id,
+            "instance_id": self.instance_id,
         })
     }
 }
@@ -295,52 +616,73 @@ impl BrokerInfo {
 pub struct NodeInfo {
     pub connections: isize,
     pub boottime: String,
+    /// `boottime` rendered per the requested [`TimeFormat`] (absolute or relative).
+    pub boottime_display: String,
     pub load1: f32,
     pub load5: f32,
     pub load15: f32,
-    // pub max_fds: usize,
-    // pub cpu_num: String,
-    // pub cpu_speed: String,
+    pub max_fds: u64,
+    pub cpu_num: usize,
+    pub cpu_speed: u64,
     pub memory_total: u64,
     pub memory_used: u64,
     pub memory_free: u64,
+    /// Filtered (pseudo-fs excluded) total across all real mounts; kept for backward
+    /// compatibility with callers that only want one number.
     pub disk_total: u64,
     pub disk_free: u64,
-    // pub os_release: String,
-    // pub os_type: String,
-    // pub proc_total: String,
+    /// Disk stats for the specific mount backing [`Node::set_data_dir`], if it could be
+    /// determined.
+    pub data_disk: Option<DiskInfo>,
+    /// Per-mount breakdown, pseudo filesystems (tmpfs, proc, sysfs, overlay, devfs, ...)
+    /// filtered out.
+    pub disks: Vec<DiskInfo>,
+    pub os_release: String,
+    pub os_type: String,
+    pub proc_total: usize,
     pub node_status: NodeStatus,
     pub node_id: NodeId,
     pub node_name: String,
     pub uptime: String,
+    pub uptime_secs: i64,
     pub version: String,
     pub rustc_version: String,
 }
 
 impl NodeInfo {
+    /// Seconds of uptime, as used by the metrics exporter.
+    #[inline]
+    pub fn uptime_secs(&self) -> i64 {
+        self.uptime_secs
+    }
+
     #[inline]
     pub fn to_json(&self) -> serde_json::Value {
         json!({
             "connections":  self.connections,
             "boottime":  self.boottime,
+            "boottime_display":  self.boottime_display,
             "load1":  self.load1,
             "load5":  self.load5,
             "load15":  self.load15,
-            // "max_fds":  self.max_fds,
-            // "cpu_num":  self.cpu_num,
-            // "cpu_speed":  self.cpu_speed,
+            "max_fds":  self.max_fds,
+            "cpu_num":  self.cpu_num,
+            "cpu_speed":  self.cpu_speed,
             "memory_total":  self.memory_total,
             "memory_used":  self.memory_used,
             "memory_free":  self.memory_free,
             "disk_total":  self.disk_total,
             "disk_free":  self.disk_free,
-            // "os_release":  self.os_release,
-            // "os_type":  self.os_type,
-            // "proc_total":  self.proc_total,
+            "data_disk":  self.data_disk,
+            "disks":  self.disks,
+            "os_release":  self.os_release,
+            "os_type":  self.os_type,
+            "proc_total":  self.proc_total,
             "running":  self.node_status.is_running(),
             "node_id":  self.node_id,
             "node_name":  self.node_name,
             "uptime":  self.uptime,
+            "uptime_secs":  self.uptime_secs,
             "version":  self.version,
             "rustc_version": self.rustc_version,
         })
@@ -351,11 +693,15 @@ impl NodeInfo {
         self.load1 += other.load1;
         self.load5 += other.load5;
         self.load15 += other.load15;
+        self.max_fds += other.max_fds;
+        self.cpu_num += other.cpu_num;
+        self.proc_total += other.proc_total;
         self.memory_total += other.memory_total;
         self.memory_used += other.memory_used;
         self.memory_free += other.memory_free;
         self.disk_total += other.disk_total;
         self.disk_free += other.disk_free;
+        self.uptime_secs = self.uptime_secs.max(other.uptime_secs);
         self.node_status = {
             let c = match (&self.node_status, &other.node_status) {
                 (NodeStatus::Running(c1), NodeStatus::Running(c2)) => *c1 + *c2,
@@ -399,6 +745,135 @@ impl Default for NodeStatus {
     }
 }
 
+/// A cluster member's last known identity, tracked internally from [`Node::record_heartbeat`].
+#[derive(Debug, Clone)]
+struct ClusterMember {
+    node_id: NodeId,
+    node_name: String,
+    hostname: String,
+    grpc_addr: String,
+    last_seen: TimestampMillis,
+}
+
+/// Liveness snapshot of a single cluster member, as returned by
+/// [`Node::handle_get_cluster_status`].
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ClusterMemberStatus {
+    pub node_id: NodeId,
+    pub node_name: String,
+    pub hostname: String,
+    pub grpc_addr: String,
+    pub is_up: bool,
+    pub last_seen_secs_ago: i64,
+    /// The last-seen time rendered per the requested [`TimeFormat`]: a local
+    /// `%Y-%m-%d %H:%M:%S` datetime when absolute, a timeago-style string when relative.
+    pub last_seen_display: String,
+}
+
+/// Response of [`Node::handle_get_cluster_status`]: the membership/liveness detail plus
+/// the existing aggregated rollup, so callers get both in one call.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ClusterStatus {
+    pub members: Vec<ClusterMemberStatus>,
+    pub aggregated: NodeInfo,
+}
+
+/// Disk usage for a single mount point, as reported in [`NodeInfo::disks`].
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DiskInfo {
+    pub mount_point: String,
+    pub fs_type: String,
+    pub total: u64,
+    pub free: u64,
+    pub used: u64,
+}
+
+/// Pseudo filesystems that don't represent real storage and shouldn't count toward disk
+/// usage totals (they double-count or misreport capacity via overlay/bind mounts).
+const PSEUDO_FS_TYPES: &[&str] = &["tmpfs", "proc", "sysfs", "overlay", "devfs", "devtmpfs", "cgroup", "cgroup2"];
+
+fn is_pseudo_fs(fs_type: &str) -> bool {
+    PSEUDO_FS_TYPES.contains(&fs_type)
+}
+
+/// Selects how [`Node::node_info`]/[`Node::broker_info`] render timestamp fields, so
+/// dashboards can pick the friendlier representation without reimplementing duration
+/// math client-side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum TimeFormat {
+    /// The raw absolute timestamp string (unchanged default behavior).
+    #[default]
+    Absolute,
+    /// A timeago-style humanized string, e.g. "3 days ago", "just now".
+    Relative,
+}
+
+impl TimeFormat {
+    /// Renders `timestamp_millis` per this format, falling back to `absolute` when this
+    /// is [`TimeFormat::Absolute`].
+    fn render(&self, timestamp_millis: TimestampMillis, absolute: String) -> String {
+        match self {
+            TimeFormat::Absolute => absolute,
+            TimeFormat::Relative => humanize_ago(timestamp_millis),
+        }
+    }
+}
+
+/// Formats `n unit ago`, pluralizing `unit` with a trailing "s" unless `n == 1`.
+fn pluralize(n: i64, unit: &str) -> String {
+    if n == 1 {
+        format!("1 {unit} ago")
+    } else {
+        format!("{n} {unit}s ago")
+    }
+}
+
+/// Renders a past timestamp (millis since epoch) as a timeago-style relative string.
+fn humanize_ago(timestamp_millis: TimestampMillis) -> String {
+    let secs_ago = (crate::utils::timestamp_millis() - timestamp_millis).max(0) / 1000;
+    match secs_ago {
+        0..=4 => "just now".to_string(),
+        5..=59 => pluralize(secs_ago, "second"),
+        60..=3599 => pluralize(secs_ago / 60, "minute"),
+        3600..=86399 => pluralize(secs_ago / 3600, "hour"),
+        _ => pluralize(secs_ago / 86400, "day"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ago(secs: i64) -> String {
+        humanize_ago(crate::utils::timestamp_millis() - secs * 1000)
+    }
+
+    #[test]
+    fn humanize_ago_bucket_edges() {
+        assert_eq!(ago(0), "just now");
+        assert_eq!(ago(4), "just now");
+        assert_eq!(ago(5), "5 seconds ago");
+        assert_eq!(ago(59), "59 seconds ago");
+        assert_eq!(ago(60), "1 minute ago");
+        assert_eq!(ago(3599), "59 minutes ago");
+        assert_eq!(ago(3600), "1 hour ago");
+        assert_eq!(ago(86399), "23 hours ago");
+        assert_eq!(ago(86400), "1 day ago");
+        assert_eq!(ago(2 * 86400), "2 days ago");
+    }
+
+    #[test]
+    fn is_pseudo_fs_classifies_known_virtual_filesystems() {
+        assert!(is_pseudo_fs("tmpfs"));
+        assert!(is_pseudo_fs("overlay"));
+        assert!(is_pseudo_fs("cgroup2"));
+        assert!(!is_pseudo_fs("ext4"));
+        assert!(!is_pseudo_fs("xfs"));
+        assert!(!is_pseudo_fs(""));
+    }
+}
+
 #[inline]
 pub fn to_uptime(uptime: i64) -> String {
     let uptime_secs = uptime % 60;
@@ -0,0 +1,264 @@
+//! Background worker registry with lifecycle visibility
+//!
+//! `update_cpuload`/`start_grpc_server` used to be bare `tokio::spawn`s with no way to
+//! tell whether the task was still alive. This module gives every long-running
+//! background task (cpuload sampler, busy-state refresher, gRPC server, future
+//! scrub/resync jobs) a common [`Worker`] trait and a [`WorkerRegistry`] that tracks each
+//! one's lifecycle state (Active/Idle/Dead), completed iterations and last error, plus a
+//! command channel so a worker can be paused/resumed/cancelled at runtime.
+
+use std::sync::atomic::{AtomicU64, AtomicU8, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tokio::sync::{mpsc, RwLock};
+
+/// One iteration of background work, implemented by each long-running task.
+///
+/// The registry's supervisor loop calls [`Worker::run`] repeatedly; a single call should
+/// perform one unit of work (e.g. one cpuload sample) and return, so the supervisor can
+/// observe progress and react to [`WorkerCommand`]s between iterations.
+#[async_trait::async_trait]
+pub trait Worker: Send + Sync + 'static {
+    /// Stable, human-readable name used to identify this worker in listings and commands.
+    fn name(&self) -> &str;
+
+    /// Runs a single iteration of work.
+    async fn run(&self) -> crate::Result<()>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WorkerState {
+    /// Currently executing (or about to run) an iteration.
+    Active,
+    /// Paused via [`WorkerCommand::Pause`]; not calling `run` until resumed.
+    Idle,
+    /// The supervisor loop has exited, either via [`WorkerCommand::Cancel`] or because
+    /// `run` returned an error. A task registered with
+    /// [`WorkerRegistry::spawn_long_running`] is `Dead` once its future has finished,
+    /// whether it succeeded or failed.
+    Dead,
+}
+
+impl From<u8> for WorkerState {
+    fn from(v: u8) -> Self {
+        match v {
+            0 => WorkerState::Active,
+            1 => WorkerState::Idle,
+            _ => WorkerState::Dead,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum WorkerCommand {
+    Pause,
+    Resume,
+    Cancel,
+}
+
+/// Snapshot of a worker's lifecycle, as returned by [`WorkerRegistry::list`]. No gRPC
+/// method exposes it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkerStatus {
+    pub name: String,
+    pub state: WorkerState,
+    pub iterations: u64,
+    pub last_error: Option<String>,
+}
+
+impl WorkerStatus {
+    pub fn to_json(&self) -> serde_json::Value {
+        json!({
+            "name": self.name,
+            "state": self.state,
+            "iterations": self.iterations,
+            "last_error": self.last_error,
+        })
+    }
+}
+
+struct WorkerEntry {
+    name: String,
+    state: AtomicU8,
+    iterations: AtomicU64,
+    last_error: RwLock<Option<String>>,
+    cmd_tx: mpsc::Sender<WorkerCommand>,
+}
+
+impl WorkerEntry {
+    async fn status(&self) -> WorkerStatus {
+        WorkerStatus {
+            name: self.name.clone(),
+            state: WorkerState::from(self.state.load(Ordering::Relaxed)),
+            iterations: self.iterations.load(Ordering::Relaxed),
+            last_error: self.last_error.read().await.clone(),
+        }
+    }
+}
+
+/// Tracks every long-running background task on a [`crate::context::ServerContext`].
+#[derive(Default)]
+pub struct WorkerRegistry {
+    entries: RwLock<Vec<Arc<WorkerEntry>>>,
+}
+
+impl WorkerRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `worker` and spawns its supervisor loop, calling `run` every `interval`.
+    ///
+    /// The loop transitions the worker to `Dead` and stops once `run` returns an error,
+    /// recording the error string for the last status report.
+    pub async fn spawn<W: Worker>(&self, worker: W, interval: Duration) {
+        let (cmd_tx, mut cmd_rx) = mpsc::channel(8);
+        let entry = Arc::new(WorkerEntry {
+            name: worker.name().to_string(),
+            state: AtomicU8::new(WorkerState::Active as u8),
+            iterations: AtomicU64::new(0),
+            last_error: RwLock::new(None),
+            cmd_tx,
+        });
+
+        self.entries.write().await.push(entry.clone());
+
+        tokio::spawn(async move {
+            let mut paused = false;
+            loop {
+                tokio::select! {
+                    cmd = cmd_rx.recv() => {
+                        match cmd {
+                            Some(WorkerCommand::Pause) => {
+                                paused = true;
+                                entry.state.store(WorkerState::Idle as u8, Ordering::Relaxed);
+                            }
+                            Some(WorkerCommand::Resume) => {
+                                paused = false;
+                                entry.state.store(WorkerState::Active as u8, Ordering::Relaxed);
+                            }
+                            Some(WorkerCommand::Cancel) | None => {
+                                entry.state.store(WorkerState::Dead as u8, Ordering::Relaxed);
+                                return;
+                            }
+                        }
+                    }
+                    _ = tokio::time::sleep(interval), if !paused => {
+                        match worker.run().await {
+                            Ok(()) => {
+                                entry.iterations.fetch_add(1, Ordering::Relaxed);
+                            }
+                            Err(e) => {
+                                log::error!("worker {} failed: {e:?}", worker.name());
+                                *entry.last_error.write().await = Some(e.to_string());
+                                entry.state.store(WorkerState::Dead as u8, Ordering::Relaxed);
+                                return;
+                            }
+                        }
+                    }
+                }
+            }
+        });
+    }
+
+    /// Lists every registered worker's name, state, iterations and last error.
+    pub async fn list(&self) -> Vec<WorkerStatus> {
+        let entries = self.entries.read().await;
+        let mut statuses = Vec::with_capacity(entries.len());
+        for entry in entries.iter() {
+            statuses.push(entry.status().await);
+        }
+        statuses
+    }
+
+    /// Sends a pause/resume/cancel command to the named worker; `false` if no such worker,
+    /// or if it no longer receives commands (it is dead, or was registered with
+    /// [`WorkerRegistry::spawn_long_running`]).
+    pub async fn command(&self, name: &str, cmd: WorkerCommand) -> bool {
+        let entries = self.entries.read().await;
+        if let Some(entry) = entries.iter().find(|e| e.name == name) {
+            entry.cmd_tx.send(cmd).await.is_ok()
+        } else {
+            false
+        }
+    }
+
+    /// Registers a single long-running task that runs `fut` to completion once, rather
+    /// than calling `run` repeatedly like [`WorkerRegistry::spawn`] does.
+    ///
+    /// This fits tasks like the gRPC server's `listen_and_serve`, which blocks for the
+    /// life of the process instead of doing discrete iterations. There's no pause/resume
+    /// support for these — only the name/state/last-error visibility applies.
+    pub async fn spawn_long_running<Fut>(&self, name: impl Into<String>, fut: Fut)
+    where
+        Fut: std::future::Future<Output = crate::Result<()>> + Send + 'static,
+    {
+        let name = name.into();
+        let (cmd_tx, _cmd_rx) = mpsc::channel(1);
+        let entry = Arc::new(WorkerEntry {
+            name: name.clone(),
+            state: AtomicU8::new(WorkerState::Active as u8),
+            iterations: AtomicU64::new(0),
+            last_error: RwLock::new(None),
+            cmd_tx,
+        });
+
+        self.entries.write().await.push(entry.clone());
+
+        tokio::spawn(async move {
+            match fut.await {
+                Ok(()) => {
+                    entry.iterations.fetch_add(1, Ordering::Relaxed);
+                }
+                Err(e) => {
+                    log::error!("worker {name} failed: {e:?}");
+                    *entry.last_error.write().await = Some(e.to_string());
+                }
+            }
+            entry.state.store(WorkerState::Dead as u8, Ordering::Relaxed);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn worker_state_roundtrips_through_u8() {
+        assert_eq!(WorkerState::from(WorkerState::Active as u8), WorkerState::Active);
+        assert_eq!(WorkerState::from(WorkerState::Idle as u8), WorkerState::Idle);
+        assert_eq!(WorkerState::from(WorkerState::Dead as u8), WorkerState::Dead);
+    }
+
+    #[test]
+    fn worker_state_unknown_byte_defaults_to_dead() {
+        assert_eq!(WorkerState::from(2), WorkerState::Dead);
+        assert_eq!(WorkerState::from(255), WorkerState::Dead);
+    }
+
+    #[tokio::test]
+    async fn spawn_long_running_transitions_to_dead_on_success() {
+        let registry = WorkerRegistry::new();
+        registry.spawn_long_running("noop", async { Ok(()) }).await;
+        tokio::task::yield_now().await;
+        let statuses = registry.list().await;
+        assert_eq!(statuses.len(), 1);
+        assert_eq!(statuses[0].name, "noop");
+        assert_eq!(statuses[0].state, WorkerState::Dead);
+    }
+
+    #[tokio::test]
+    async fn spawn_long_running_records_last_error_on_failure() {
+        let registry = WorkerRegistry::new();
+        registry.spawn_long_running("boom", async { Err(anyhow::anyhow!("kaboom")) }).await;
+        tokio::task::yield_now().await;
+        let statuses = registry.list().await;
+        assert_eq!(statuses[0].state, WorkerState::Dead);
+        assert!(statuses[0].last_error.as_deref().unwrap_or_default().contains("kaboom"));
+    }
+}